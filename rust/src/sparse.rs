@@ -1,12 +1,38 @@
+use std::fmt::{self, Debug};
+use std::fs::File;
 use std::hash::Hash;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::ops::{Add, Mul};
+use std::path::Path;
+use std::str::FromStr;
 
 use fxhash::FxHashMap;
 use pyo3::prelude::FromPyObject;
 use serde::{Deserialize, Serialize};
 
+/// Converts a generic index type to `usize`, used when a coordinate stored as
+/// `T` needs to address a dense position (e.g. a row's slot in `indptr`).
+fn to_usize<T>(index: T) -> usize
+where
+    T: TryInto<usize>,
+    <T as TryInto<usize>>::Error: Debug,
+{
+    index.try_into().expect("index out of range for usize")
+}
+
+/// The inverse of [`to_usize`], converting a dense position back to the
+/// coordinate type `T`.
+fn from_usize<T>(index: usize) -> T
+where
+    T: TryFrom<usize>,
+    <T as TryFrom<usize>>::Error: Debug,
+{
+    T::try_from(index).expect("index out of range for T")
+}
+
 /// Analogy of `scipy.sparse.csr_matrix`
 /// https://docs.scipy.org/doc/scipy/reference/generated/scipy.sparse.csr_matrix.html
-#[derive(FromPyObject, Serialize, Deserialize)]
+#[derive(Debug, FromPyObject, Serialize, Deserialize)]
 pub struct CsrMatrix<T, U> {
     #[pyo3(attribute("sparse_indices"))]
     pub indices: Vec<T>,
@@ -26,7 +52,12 @@ impl<T: Copy + Eq + Hash + Ord, U: Copy> CsrMatrix<T, U> {
         self.indptr.len() - 1
     }
 
-    fn to_dok(&self, n_rows: Option<usize>) -> DokMatrix<T, U> {
+    /// Backs [`CsrMatrix::add`] and [`CsrMatrix::add_overwrite`] via
+    /// per-row hash maps rather than [`CooMatrix`] accumulation: `DokMatrix`
+    /// supports both sum-on-overlap and overwrite-on-overlap merges, while
+    /// `CooMatrix::to_csr` only ever sums duplicates, which would silently
+    /// change `add_overwrite`'s semantics.
+    pub(crate) fn to_dok(&self, n_rows: Option<usize>) -> DokMatrix<T, U> {
         let mut data = Vec::new();
         let n_rows = n_rows.unwrap_or_else(|| self.n_rows());
         for i in 0..n_rows {
@@ -39,16 +70,18 @@ impl<T: Copy + Eq + Hash + Ord, U: Copy> CsrMatrix<T, U> {
         DokMatrix { data }
     }
 
-    pub fn add(
+    /// Overwrites `this` with any overlapping nonzero from `other`, keeping the old
+    /// `insert`-based merge behavior for callers that rely on it.
+    pub fn add_overwrite(
         this: &CsrMatrix<T, U>,
         other: &CsrMatrix<T, U>,
         n_rows: Option<usize>,
     ) -> CsrMatrix<T, U> {
         let mut dok_matrix = this.to_dok(n_rows);
-        dok_matrix.add(other).to_csr()
+        dok_matrix.add_overwrite(other).to_csr()
     }
 
-    fn iter(&self) -> CsrMatrixIterator<T, U> {
+    fn iter(&self) -> CsrMatrixIterator<'_, T, U> {
         CsrMatrixIterator {
             matrix: self,
             row_idx: 0,
@@ -56,6 +89,402 @@ impl<T: Copy + Eq + Hash + Ord, U: Copy> CsrMatrix<T, U> {
     }
 }
 
+impl<T: Copy + Eq + Hash + Ord, U: Copy + Add<Output = U>> CsrMatrix<T, U> {
+    /// Merges `other` into `this`, summing values that share a nonzero coordinate,
+    /// matching `scipy.sparse` addition semantics.
+    pub fn add(
+        this: &CsrMatrix<T, U>,
+        other: &CsrMatrix<T, U>,
+        n_rows: Option<usize>,
+    ) -> CsrMatrix<T, U> {
+        let mut dok_matrix = this.to_dok(n_rows);
+        dok_matrix.add(other).to_csr()
+    }
+}
+
+impl<T, U> CsrMatrix<T, U>
+where
+    T: Copy + Eq + Hash + Ord + TryFrom<usize>,
+    <T as TryFrom<usize>>::Error: Debug,
+    U: Copy,
+{
+    /// Converts to the coordinate (triplet) format. `n_cols` must be supplied
+    /// since `CsrMatrix` does not track a column count of its own.
+    pub fn to_coo(&self, n_cols: usize) -> CooMatrix<T, U> {
+        let mut coo = CooMatrix::new(self.n_rows(), n_cols);
+        for i in 0..self.n_rows() {
+            if let Some(row) = get_row(self, i) {
+                let row_idx = from_usize(i);
+                for (col, val) in row {
+                    coo.push(row_idx, col, val);
+                }
+            }
+        }
+        coo
+    }
+}
+
+impl<T, U> CsrMatrix<T, U>
+where
+    T: Copy + Eq + Hash + Ord + TryInto<usize> + TryFrom<usize>,
+    <T as TryInto<usize>>::Error: Debug,
+    <T as TryFrom<usize>>::Error: Debug,
+    U: Copy,
+{
+    /// Transposes to CSC by counting nonzeros per column to build the CSC
+    /// `indptr`, then walking the CSR rows once and placing each `(row, val)`
+    /// into the destination column's cursor position. `n_cols` must be
+    /// supplied since `CsrMatrix` does not track a column count of its own.
+    pub fn transpose_to_csc(&self, n_cols: usize) -> CscMatrix<T, U> {
+        let nnz = self.data.len();
+
+        let mut col_counts = vec![0usize; n_cols];
+        for &col in &self.indices {
+            col_counts[to_usize(col)] += 1;
+        }
+        let mut indptr = vec![0usize; n_cols + 1];
+        for c in 0..n_cols {
+            indptr[c + 1] = indptr[c] + col_counts[c];
+        }
+
+        let mut cursor = indptr.clone();
+        let mut scratch: Vec<Option<(T, U)>> = vec![None; nnz];
+        for i in 0..self.n_rows() {
+            if let Some(row) = get_row(self, i) {
+                let row_idx = from_usize(i);
+                for (col, val) in row {
+                    let c = to_usize(col);
+                    scratch[cursor[c]] = Some((row_idx, val));
+                    cursor[c] += 1;
+                }
+            }
+        }
+
+        let (indices, data) = unzip_scratch(scratch);
+        CscMatrix {
+            indices,
+            indptr,
+            data,
+        }
+    }
+}
+
+/// Unwraps a fully-populated scatter scratch buffer into separate `indices`
+/// and `data` vectors, shared by the CSR<->CSC bucketing conversions.
+fn unzip_scratch<T, U>(scratch: Vec<Option<(T, U)>>) -> (Vec<T>, Vec<U>) {
+    scratch
+        .into_iter()
+        .map(|entry| entry.expect("scratch slot was not populated during scatter"))
+        .unzip()
+}
+
+/// Errors produced while reading or writing the MatrixMarket coordinate
+/// format.
+#[derive(Debug)]
+pub enum MatrixMarketError {
+    Io(io::Error),
+    InvalidBanner(String),
+    InvalidSize(String),
+    InvalidEntry(String),
+}
+
+impl fmt::Display for MatrixMarketError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixMarketError::Io(err) => write!(f, "I/O error: {err}"),
+            MatrixMarketError::InvalidBanner(line) => {
+                write!(f, "invalid MatrixMarket banner: {line:?}")
+            }
+            MatrixMarketError::InvalidSize(line) => {
+                write!(f, "invalid MatrixMarket size line: {line:?}")
+            }
+            MatrixMarketError::InvalidEntry(line) => {
+                write!(f, "invalid MatrixMarket entry: {line:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MatrixMarketError {}
+
+impl From<io::Error> for MatrixMarketError {
+    fn from(err: io::Error) -> Self {
+        MatrixMarketError::Io(err)
+    }
+}
+
+impl<T, U> CsrMatrix<T, U>
+where
+    T: Copy + Eq + Hash + Ord + TryInto<usize> + TryFrom<usize>,
+    <T as TryInto<usize>>::Error: Debug,
+    <T as TryFrom<usize>>::Error: Debug,
+    U: Copy + Add<Output = U> + FromStr,
+{
+    /// Reads the MatrixMarket coordinate format: a `%%MatrixMarket matrix
+    /// coordinate <field> <symmetry>` banner, `%` comment lines, a
+    /// `rows cols nnz` size line, then `nnz` lines of 1-based `row col value`
+    /// entries. The `pattern` field (no value column) implies a value of `1`;
+    /// the `symmetric` qualifier mirrors `(i, j)` into `(j, i)`.
+    pub fn from_matrix_market<P: AsRef<Path>>(
+        path: P,
+    ) -> Result<CsrMatrix<T, U>, MatrixMarketError> {
+        let file = File::open(path)?;
+        let mut lines = BufReader::new(file).lines();
+
+        let banner = lines
+            .next()
+            .ok_or_else(|| MatrixMarketError::InvalidBanner(String::new()))??;
+        let banner_fields: Vec<&str> = banner.split_whitespace().collect();
+        if banner_fields.len() != 5
+            || !banner_fields[0].eq_ignore_ascii_case("%%MatrixMarket")
+            || !banner_fields[1].eq_ignore_ascii_case("matrix")
+            || !banner_fields[2].eq_ignore_ascii_case("coordinate")
+        {
+            return Err(MatrixMarketError::InvalidBanner(banner));
+        }
+        let is_pattern = banner_fields[3].eq_ignore_ascii_case("pattern");
+        let is_symmetric = banner_fields[4].eq_ignore_ascii_case("symmetric");
+
+        let mut size_line = None;
+        for line in &mut lines {
+            let line = line?;
+            if line.trim().is_empty() || line.starts_with('%') {
+                continue;
+            }
+            size_line = Some(line);
+            break;
+        }
+        let size_line =
+            size_line.ok_or_else(|| MatrixMarketError::InvalidSize(String::new()))?;
+        let size_fields: Vec<&str> = size_line.split_whitespace().collect();
+        if size_fields.len() != 3 {
+            return Err(MatrixMarketError::InvalidSize(size_line));
+        }
+        let parse_size = |field: &str| -> Result<usize, MatrixMarketError> {
+            field
+                .parse()
+                .map_err(|_| MatrixMarketError::InvalidSize(size_line.clone()))
+        };
+        let n_rows = parse_size(size_fields[0])?;
+        let n_cols = parse_size(size_fields[1])?;
+        let nnz = parse_size(size_fields[2])?;
+
+        let mut coo = CooMatrix::new(n_rows, n_cols);
+        let mut read = 0;
+        for line in lines {
+            if read >= nnz {
+                break;
+            }
+            let line = line?;
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('%') {
+                continue;
+            }
+            let fields: Vec<&str> = trimmed.split_whitespace().collect();
+            let min_fields = if is_pattern { 2 } else { 3 };
+            if fields.len() < min_fields {
+                return Err(MatrixMarketError::InvalidEntry(line));
+            }
+            let invalid_entry = || MatrixMarketError::InvalidEntry(line.clone());
+            let row: usize = fields[0].parse().map_err(|_| invalid_entry())?;
+            let col: usize = fields[1].parse().map_err(|_| invalid_entry())?;
+            if row == 0 || col == 0 {
+                return Err(invalid_entry());
+            }
+            let val = if is_pattern {
+                "1".parse::<U>().map_err(|_| invalid_entry())?
+            } else {
+                fields[2].parse().map_err(|_| invalid_entry())?
+            };
+
+            if row > n_rows || col > n_cols {
+                return Err(invalid_entry());
+            }
+
+            let row_idx = from_usize(row - 1);
+            let col_idx = from_usize(col - 1);
+            coo.push(row_idx, col_idx, val);
+            if is_symmetric && row != col {
+                if col > n_rows || row > n_cols {
+                    return Err(invalid_entry());
+                }
+                coo.push(col_idx, row_idx, val);
+            }
+            read += 1;
+        }
+
+        Ok(coo.to_csr())
+    }
+}
+
+impl<T, U> CsrMatrix<T, U>
+where
+    T: Copy + Eq + Hash + Ord + TryInto<usize>,
+    <T as TryInto<usize>>::Error: Debug,
+    U: Copy + fmt::Display,
+{
+    /// Writes the general (non-symmetric) MatrixMarket coordinate format.
+    /// `n_cols` must be supplied since `CsrMatrix` does not track a column
+    /// count of its own.
+    pub fn write_matrix_market<P: AsRef<Path>>(
+        &self,
+        path: P,
+        n_cols: usize,
+    ) -> Result<(), MatrixMarketError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writeln!(writer, "%%MatrixMarket matrix coordinate real general")?;
+        writeln!(writer, "{} {} {}", self.n_rows(), n_cols, self.data.len())?;
+        for i in 0..self.n_rows() {
+            if let Some(row) = get_row(self, i) {
+                for (col, val) in row {
+                    writeln!(writer, "{} {} {}", i + 1, to_usize(col) + 1, val)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T, U> CsrMatrix<T, U>
+where
+    T: Copy + Eq + Hash + Ord + TryInto<usize> + TryFrom<usize>,
+    <T as TryInto<usize>>::Error: Debug,
+    <T as TryFrom<usize>>::Error: Debug,
+    U: Copy + Add<Output = U> + Mul<Output = U>,
+{
+    /// Computes `self · other` via Gustavson's row-by-row SpGEMM algorithm.
+    /// For each row `i` of `self`, every `(k, a_ik)` fans out over row `k` of
+    /// `other`, accumulating `a_ik * b_kj` into a dense scatter workspace
+    /// (reused across rows, sized `other_n_cols`) keyed by `j`. Touched
+    /// columns are tracked in a scratch list, sorted, and emitted into the
+    /// result row before the touched workspace slots are cleared. `other_n_cols`
+    /// must be supplied since `CsrMatrix` does not track a column count of its
+    /// own.
+    pub fn matmul(&self, other: &CsrMatrix<T, U>, other_n_cols: usize) -> CsrMatrix<T, U> {
+        let mut indices = Vec::new();
+        let mut data = Vec::new();
+        let mut indptr = vec![0usize; self.n_rows() + 1];
+
+        let mut workspace: Vec<Option<U>> = vec![None; other_n_cols];
+        let mut touched: Vec<usize> = Vec::new();
+
+        for i in 0..self.n_rows() {
+            touched.clear();
+            if let Some(row) = get_row(self, i) {
+                for (k, a_ik) in row {
+                    let k = to_usize(k);
+                    if let Some(other_row) = get_row(other, k) {
+                        for (j, b_kj) in other_row {
+                            let j = to_usize(j);
+                            let product = a_ik * b_kj;
+                            match workspace[j] {
+                                Some(existing) => workspace[j] = Some(existing + product),
+                                None => {
+                                    workspace[j] = Some(product);
+                                    touched.push(j);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            touched.sort_unstable();
+            for &j in &touched {
+                indices.push(from_usize(j));
+                data.push(workspace[j].take().expect("touched column was not set"));
+            }
+            indptr[i + 1] = indices.len();
+        }
+
+        CsrMatrix {
+            indices,
+            indptr,
+            data,
+        }
+    }
+}
+
+/// Errors produced by [`CsrMatrix::validate`] when the CSR invariants don't
+/// hold.
+#[derive(Debug, PartialEq, Eq)]
+pub enum StructureError {
+    /// `indptr` has fewer than one entry.
+    IndptrEmpty,
+    /// `indptr` is not non-decreasing.
+    IndptrNotNonDecreasing,
+    /// `indptr`'s last entry doesn't match `indices.len()` / `data.len()`.
+    IndptrLengthMismatch,
+    /// A row's column index is `>= n_cols`.
+    ColumnOutOfBounds,
+    /// A row's column indices are not strictly increasing.
+    ColumnsNotIncreasing,
+}
+
+impl fmt::Display for StructureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            StructureError::IndptrEmpty => "indptr must have at least one entry",
+            StructureError::IndptrNotNonDecreasing => "indptr must be non-decreasing",
+            StructureError::IndptrLengthMismatch => {
+                "indptr's last entry must equal indices.len() and data.len()"
+            }
+            StructureError::ColumnOutOfBounds => "a row's column index is out of bounds",
+            StructureError::ColumnsNotIncreasing => {
+                "a row's column indices must be strictly increasing"
+            }
+        };
+        write!(f, "{message}")
+    }
+}
+
+impl std::error::Error for StructureError {}
+
+impl<T, U> CsrMatrix<T, U>
+where
+    T: Copy + Eq + Hash + Ord + TryInto<usize>,
+    <T as TryInto<usize>>::Error: Debug,
+    U: Copy,
+{
+    /// Checks that `indptr` is non-decreasing, that its last entry matches
+    /// `indices.len()` and `data.len()`, and that every row's column indices
+    /// are in `[0, n_cols)` and strictly increasing.
+    pub fn validate(&self, n_cols: usize) -> Result<(), StructureError> {
+        if self.indptr.is_empty() {
+            return Err(StructureError::IndptrEmpty);
+        }
+        for window in self.indptr.windows(2) {
+            if window[1] < window[0] {
+                return Err(StructureError::IndptrNotNonDecreasing);
+            }
+        }
+        let nnz = *self.indptr.last().unwrap();
+        if nnz != self.indices.len() || nnz != self.data.len() {
+            return Err(StructureError::IndptrLengthMismatch);
+        }
+
+        for i in 0..self.n_rows() {
+            let start = self.indptr[i];
+            let end = self.indptr[i + 1];
+            let mut prev_col: Option<usize> = None;
+            for &col in &self.indices[start..end] {
+                let col: usize = match col.try_into() {
+                    Ok(col) => col,
+                    Err(_) => return Err(StructureError::ColumnOutOfBounds),
+                };
+                if col >= n_cols {
+                    return Err(StructureError::ColumnOutOfBounds);
+                }
+                if prev_col.is_some_and(|prev| col <= prev) {
+                    return Err(StructureError::ColumnsNotIncreasing);
+                }
+                prev_col = Some(col);
+            }
+        }
+        Ok(())
+    }
+}
+
 struct CsrMatrixIterator<'a, T, U> {
     matrix: &'a CsrMatrix<T, U>,
     row_idx: usize,
@@ -75,6 +504,10 @@ where
     }
 }
 
+/// Returns `None` only when `i` is out of range; an in-range row yields
+/// `Some` even when it has no nonzeros, so callers iterating row-by-row (e.g.
+/// [`CsrMatrixIterator`]) can tell "no more rows" apart from "this row is
+/// empty" and don't stop early on an interior empty row.
 pub(crate) fn get_row<'a, T, U>(
     matrix: &'a CsrMatrix<T, U>,
     i: usize,
@@ -88,11 +521,7 @@ where
     }
     let start = matrix.indptr[i];
     let end = matrix.indptr[i + 1];
-    if start == end {
-        None
-    } else {
-        Some(index_iter(start, end, &matrix.indices, &matrix.data))
-    }
+    Some(index_iter(start, end, &matrix.indices, &matrix.data))
 }
 
 fn index_iter<'a, T, U>(
@@ -128,7 +557,8 @@ where
     T: Copy + Eq + Hash + Ord,
     U: Copy,
 {
-    fn add(&mut self, other: &CsrMatrix<T, U>) -> &Self {
+    /// Overwrites any existing value at a shared coordinate instead of summing it.
+    fn add_overwrite(&mut self, other: &CsrMatrix<T, U>) -> &Self {
         for (i, row) in other.iter().enumerate() {
             for (idx, dat) in row {
                 let mapping = &mut self.data[i];
@@ -138,19 +568,22 @@ where
         self
     }
 
-    fn to_csr(&self) -> CsrMatrix<T, U> {
+    pub(crate) fn to_csr(&self) -> CsrMatrix<T, U> {
         let mut indices: Vec<T> = Vec::new();
         let mut indptr: Vec<usize> = vec![0];
         let mut data: Vec<U> = Vec::new();
         for d in self.data.iter() {
-            if d.is_empty() {
-                continue;
+            if !d.is_empty() {
+                let mut mapping: Vec<(&T, &U)> = d.iter().collect();
+                mapping.sort_unstable_by_key(|(i, _)| *i);
+                let (idx, dat): (Vec<T>, Vec<U>) = mapping.into_iter().unzip();
+                indices.extend(idx);
+                data.extend(dat);
             }
-            let mut mapping: Vec<(&T, &U)> = d.iter().collect();
-            mapping.sort_unstable_by_key(|(i, _)| *i);
-            let (idx, dat): (Vec<T>, Vec<U>) = mapping.into_iter().unzip();
-            indices.extend(idx);
-            data.extend(dat);
+            // Always push an indptr entry, even for an empty row, so row `i`'s
+            // slice stays `indices[indptr[i]..indptr[i + 1]]` for every row
+            // after it — skipping this entry for empty rows shifted every
+            // later row's data left and silently corrupted the result.
             indptr.push(indices.len());
         }
         CsrMatrix {
@@ -161,6 +594,235 @@ where
     }
 }
 
+impl<T, U> DokMatrix<T, U>
+where
+    T: Copy + Eq + Hash + Ord,
+    U: Copy + Add<Output = U>,
+{
+    /// Accumulates `other` into `self`, summing values that share a coordinate.
+    fn add(&mut self, other: &CsrMatrix<T, U>) -> &Self {
+        for (i, row) in other.iter().enumerate() {
+            for (idx, dat) in row {
+                let mapping = &mut self.data[i];
+                match mapping.get(&idx) {
+                    Some(&existing) => mapping.insert(idx, existing + dat),
+                    None => mapping.insert(idx, dat),
+                };
+            }
+        }
+        self
+    }
+}
+
+/// Analogy of `scipy.sparse.coo_matrix`, a coordinate (triplet) format that is
+/// cheap to build incrementally via [`CooMatrix::push`].
+/// https://docs.scipy.org/doc/scipy/reference/generated/scipy.sparse.coo_matrix.html
+#[derive(Debug)]
+pub struct CooMatrix<T, U> {
+    pub rows: Vec<T>,
+    pub cols: Vec<T>,
+    pub data: Vec<U>,
+    pub n_rows: usize,
+    pub n_cols: usize,
+}
+
+impl<T, U> CooMatrix<T, U>
+where
+    T: Copy + Eq + Hash + Ord,
+    U: Copy,
+{
+    pub fn new(n_rows: usize, n_cols: usize) -> Self {
+        CooMatrix {
+            rows: Vec::new(),
+            cols: Vec::new(),
+            data: Vec::new(),
+            n_rows,
+            n_cols,
+        }
+    }
+
+    pub fn push(&mut self, row: T, col: T, val: U) {
+        self.rows.push(row);
+        self.cols.push(col);
+        self.data.push(val);
+    }
+}
+
+impl<T, U> CooMatrix<T, U>
+where
+    T: Copy + Eq + Hash + Ord + TryInto<usize>,
+    <T as TryInto<usize>>::Error: Debug,
+    U: Copy + Add<Output = U>,
+{
+    /// Converts to CSR by counting nonzeros per row to build `indptr` via a
+    /// prefix sum, then scattering each `(col, val)` into its row's slice using
+    /// a running per-row cursor. Duplicate `(row, col)` pairs are merged by
+    /// summing.
+    pub fn to_csr(&self) -> CsrMatrix<T, U> {
+        let nnz = self.data.len();
+
+        let mut row_counts = vec![0usize; self.n_rows];
+        for &row in &self.rows {
+            row_counts[to_usize(row)] += 1;
+        }
+        let mut indptr = vec![0usize; self.n_rows + 1];
+        for i in 0..self.n_rows {
+            indptr[i + 1] = indptr[i] + row_counts[i];
+        }
+
+        let mut cursor = indptr.clone();
+        let mut scratch: Vec<Option<(T, U)>> = vec![None; nnz];
+        for ((&row, &col), &val) in self.rows.iter().zip(&self.cols).zip(&self.data) {
+            let r = to_usize(row);
+            scratch[cursor[r]] = Some((col, val));
+            cursor[r] += 1;
+        }
+
+        let mut indices = Vec::with_capacity(nnz);
+        let mut data = Vec::with_capacity(nnz);
+        let mut final_indptr = vec![0usize; self.n_rows + 1];
+        for i in 0..self.n_rows {
+            let mut row_entries: Vec<(T, U)> = scratch[indptr[i]..indptr[i + 1]]
+                .iter()
+                .map(|entry| entry.expect("scratch slot was not populated during scatter"))
+                .collect();
+            row_entries.sort_unstable_by_key(|(col, _)| *col);
+
+            let row_start = final_indptr[i];
+            for (col, val) in row_entries {
+                if indices.len() > row_start && *indices.last().unwrap() == col {
+                    let last = data.last_mut().unwrap();
+                    *last = *last + val;
+                } else {
+                    indices.push(col);
+                    data.push(val);
+                }
+            }
+            final_indptr[i + 1] = indices.len();
+        }
+
+        CsrMatrix {
+            indices,
+            indptr: final_indptr,
+            data,
+        }
+    }
+}
+
+/// Analogy of `scipy.sparse.csc_matrix`, mirroring `CsrMatrix` but with
+/// `indptr` over columns and row indices within each column instead.
+/// https://docs.scipy.org/doc/scipy/reference/generated/scipy.sparse.csc_matrix.html
+#[derive(Debug)]
+pub struct CscMatrix<T, U> {
+    pub indices: Vec<T>,
+    pub indptr: Vec<usize>,
+    pub data: Vec<U>,
+}
+
+impl<T: Copy + Eq + Hash + Ord, U: Copy> CscMatrix<T, U> {
+    pub fn values(&self) -> (&[T], &[usize], &[U]) {
+        (&self.indices, &self.indptr, &self.data)
+    }
+
+    #[inline]
+    pub fn n_cols(&self) -> usize {
+        self.indptr.len() - 1
+    }
+}
+
+impl<T, U> CscMatrix<T, U>
+where
+    T: Copy + Eq + Hash + Ord + TryInto<usize> + TryFrom<usize>,
+    <T as TryInto<usize>>::Error: Debug,
+    <T as TryFrom<usize>>::Error: Debug,
+    U: Copy,
+{
+    /// Transposes back to CSR, the inverse of [`CsrMatrix::transpose_to_csc`].
+    /// `n_rows` must be supplied since `CscMatrix` does not track a row count
+    /// of its own.
+    pub fn to_csr(&self, n_rows: usize) -> CsrMatrix<T, U> {
+        let nnz = self.data.len();
+        let n_cols = self.n_cols();
+
+        let mut row_counts = vec![0usize; n_rows];
+        for &row in &self.indices {
+            row_counts[to_usize(row)] += 1;
+        }
+        let mut indptr = vec![0usize; n_rows + 1];
+        for r in 0..n_rows {
+            indptr[r + 1] = indptr[r] + row_counts[r];
+        }
+
+        let mut cursor = indptr.clone();
+        let mut scratch: Vec<Option<(T, U)>> = vec![None; nnz];
+        for c in 0..n_cols {
+            let start = self.indptr[c];
+            let end = self.indptr[c + 1];
+            if start == end {
+                continue;
+            }
+            let col_idx = from_usize(c);
+            for k in start..end {
+                let r = to_usize(self.indices[k]);
+                scratch[cursor[r]] = Some((col_idx, self.data[k]));
+                cursor[r] += 1;
+            }
+        }
+
+        let (indices, data) = unzip_scratch(scratch);
+        CsrMatrix {
+            indices,
+            indptr,
+            data,
+        }
+    }
+}
+
+/// Proptest strategies for generating arbitrary well-formed `CsrMatrix<i32,
+/// i32>` values, usable by this crate's own property tests and by downstream
+/// consumers under the `proptest` feature.
+#[cfg(feature = "proptest")]
+pub mod arbitrary {
+    use proptest::prelude::*;
+
+    use super::CsrMatrix;
+
+    /// Generates a single row: sorted, unique column indices in `[0, n_cols)`
+    /// paired with arbitrary data values of the same length.
+    fn arb_row(n_cols: usize) -> impl Strategy<Value = (Vec<i32>, Vec<i32>)> {
+        prop::collection::btree_set(0..n_cols as i32, 0..=n_cols).prop_flat_map(move |cols| {
+            let len = cols.len();
+            prop::collection::vec(-100i32..100, len)
+                .prop_map(move |data| (cols.iter().copied().collect(), data))
+        })
+    }
+
+    /// Generates an arbitrary `CsrMatrix<i32, i32>` by choosing a row/col
+    /// count and a per-row nonzero count with sorted unique column indices.
+    pub fn arb_csr_matrix(
+        max_rows: usize,
+        max_cols: usize,
+    ) -> impl Strategy<Value = CsrMatrix<i32, i32>> {
+        (1..=max_rows, 1..=max_cols).prop_flat_map(|(n_rows, n_cols)| {
+            prop::collection::vec(arb_row(n_cols), n_rows).prop_map(|rows| {
+                let mut indices = Vec::new();
+                let mut indptr = vec![0usize];
+                let mut data = Vec::new();
+                for (cols, vals) in rows {
+                    indices.extend(cols);
+                    data.extend(vals);
+                    indptr.push(indices.len());
+                }
+                CsrMatrix {
+                    indices,
+                    indptr,
+                    data,
+                }
+            })
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -186,17 +848,64 @@ mod tests {
             data: vec![2, 4],
         };
 
-        // [[1, 0, 0], [1, 0, 2], [3, 3, 0]]
+        // [[1, 0, 0], [1, 0, 3], [3, 3, 0]]
+        // Row 1 overlaps at col 2 (1 + 2 = 3), so the shared nonzero is summed
+        // rather than overwritten.
         matrix = CsrMatrix::add(&matrix, &matrix_large, Some(3));
         assert_eq!(matrix.indices, vec![0, 0, 2, 0, 1]);
         assert_eq!(matrix.indptr, vec![0, 1, 3, 5]);
-        assert_eq!(matrix.data, vec![1, 1, 2, 3, 3]);
+        assert_eq!(matrix.data, vec![1, 1, 3, 3, 3]);
 
-        // [[2, 0, 4], [1, 0, 2], [3, 3, 0]]
+        // [[3, 0, 4], [1, 0, 3], [3, 3, 0]]
+        // Row 0 overlaps at col 0 (1 + 2 = 3).
         matrix = CsrMatrix::add(&matrix, &matrix_small, Some(3));
         assert_eq!(matrix.indices, vec![0, 2, 0, 2, 0, 1]);
         assert_eq!(matrix.indptr, vec![0, 2, 4, 6]);
-        assert_eq!(matrix.data, vec![2, 4, 1, 2, 3, 3]);
+        assert_eq!(matrix.data, vec![3, 4, 1, 3, 3, 3]);
+    }
+
+    #[test]
+    fn test_add_overwrite_sparse_matrix() {
+        // [[1, 0, 0], [1, 0, 2], [3, 3, 0]]
+        let matrix = CsrMatrix {
+            indices: vec![0, 0, 2, 0, 1],
+            indptr: vec![0, 1, 3, 5],
+            data: vec![1, 1, 2, 3, 3],
+        };
+        // [[2, 0, 4]]
+        let matrix_small = CsrMatrix {
+            indices: vec![0, 2],
+            indptr: vec![0, 2],
+            data: vec![2, 4],
+        };
+
+        // Overlapping col 0 in row 0 is overwritten (2), not summed (3).
+        let result = CsrMatrix::add_overwrite(&matrix, &matrix_small, Some(3));
+        assert_eq!(result.indices, vec![0, 2, 0, 2, 0, 1]);
+        assert_eq!(result.indptr, vec![0, 2, 4, 6]);
+        assert_eq!(result.data, vec![2, 4, 1, 2, 3, 3]);
+    }
+
+    #[test]
+    fn test_add_overlapping_nonzeros_differently_shaped() {
+        // [[1, 2], [0, 3]]
+        let matrix = CsrMatrix {
+            indices: vec![0, 1, 1],
+            indptr: vec![0, 2, 3],
+            data: vec![1, 2, 3],
+        };
+        // [[5, 0], [1, 1], [4, 0]]
+        let matrix_other = CsrMatrix {
+            indices: vec![0, 0, 1, 0],
+            indptr: vec![0, 1, 3, 4],
+            data: vec![5, 1, 1, 4],
+        };
+
+        // [[6, 2], [1, 4], [4, 0]]
+        let result = CsrMatrix::add(&matrix, &matrix_other, Some(3));
+        assert_eq!(result.indices, vec![0, 1, 0, 1, 0]);
+        assert_eq!(result.indptr, vec![0, 2, 4, 5]);
+        assert_eq!(result.data, vec![6, 2, 1, 4, 4]);
     }
 
     #[test]
@@ -217,4 +926,306 @@ mod tests {
         };
         CsrMatrix::add(&matrix, &matrix_large, Some(new_size));
     }
+
+    #[test]
+    fn test_coo_push_to_csr() {
+        // [[1, 0, 2], [0, 0, 0], [0, 3, 0]]
+        let mut coo: CooMatrix<i32, i32> = CooMatrix::new(3, 3);
+        coo.push(0, 0, 1);
+        coo.push(0, 2, 2);
+        coo.push(2, 1, 3);
+
+        let csr = coo.to_csr();
+        assert_eq!(csr.indices, vec![0, 2, 1]);
+        assert_eq!(csr.indptr, vec![0, 2, 2, 3]);
+        assert_eq!(csr.data, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_coo_to_csr_merges_duplicates() {
+        // (0, 0) appears twice and must be summed, not overwritten.
+        let mut coo: CooMatrix<i32, i32> = CooMatrix::new(2, 2);
+        coo.push(0, 0, 1);
+        coo.push(1, 1, 5);
+        coo.push(0, 0, 4);
+
+        let csr = coo.to_csr();
+        assert_eq!(csr.indices, vec![0, 1]);
+        assert_eq!(csr.indptr, vec![0, 1, 2]);
+        assert_eq!(csr.data, vec![5, 5]);
+    }
+
+    #[test]
+    fn test_csr_to_coo_and_back() {
+        // [[1, 0, 0], [1, 0, 2], [3, 3, 0]]
+        let csr = CsrMatrix {
+            indices: vec![0, 0, 2, 0, 1],
+            indptr: vec![0, 1, 3, 5],
+            data: vec![1, 1, 2, 3, 3],
+        };
+
+        let coo = csr.to_coo(3);
+        assert_eq!(coo.rows, vec![0, 1, 1, 2, 2]);
+        assert_eq!(coo.cols, vec![0, 0, 2, 0, 1]);
+        assert_eq!(coo.data, vec![1, 1, 2, 3, 3]);
+
+        let roundtrip = coo.to_csr();
+        assert_eq!(roundtrip.indices, csr.indices);
+        assert_eq!(roundtrip.indptr, csr.indptr);
+        assert_eq!(roundtrip.data, csr.data);
+    }
+
+    #[test]
+    fn test_transpose_to_csc_and_back() {
+        // [[1, 0, 2], [0, 0, 0], [0, 3, 0]]
+        let csr = CsrMatrix {
+            indices: vec![0, 2, 1],
+            indptr: vec![0, 2, 2, 3],
+            data: vec![1, 2, 3],
+        };
+
+        // columns: col0 -> row0=1, col1 -> row2=3, col2 -> row0=2
+        let csc = csr.transpose_to_csc(3);
+        assert_eq!(csc.indices, vec![0, 2, 0]);
+        assert_eq!(csc.indptr, vec![0, 1, 2, 3]);
+        assert_eq!(csc.data, vec![1, 3, 2]);
+
+        let roundtrip = csc.to_csr(3);
+        assert_eq!(roundtrip.indices, csr.indices);
+        assert_eq!(roundtrip.indptr, csr.indptr);
+        assert_eq!(roundtrip.data, csr.data);
+    }
+
+    fn mm_test_path(name: &str) -> std::path::PathBuf {
+        let thread_id = std::thread::current().id();
+        std::env::temp_dir().join(format!("sparse_mm_test_{name}_{thread_id:?}.mtx"))
+    }
+
+    #[test]
+    fn test_matrix_market_write_and_read_roundtrip() {
+        // [[1, 0, 0], [1, 0, 2], [3, 3, 0]]
+        let csr: CsrMatrix<i32, f64> = CsrMatrix {
+            indices: vec![0, 0, 2, 0, 1],
+            indptr: vec![0, 1, 3, 5],
+            data: vec![1.0, 1.0, 2.0, 3.0, 3.0],
+        };
+        let path = mm_test_path("roundtrip");
+
+        csr.write_matrix_market(&path, 3).unwrap();
+        let read_back: CsrMatrix<i32, f64> = CsrMatrix::from_matrix_market(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(read_back.indices, csr.indices);
+        assert_eq!(read_back.indptr, csr.indptr);
+        assert_eq!(read_back.data, csr.data);
+    }
+
+    #[test]
+    fn test_matrix_market_symmetric_and_pattern() {
+        let path = mm_test_path("symmetric_pattern");
+        // A 3x3 symmetric pattern matrix with nonzeros at (1,1) and (3,2).
+        std::fs::write(
+            &path,
+            "%%MatrixMarket matrix coordinate pattern symmetric\n% a comment\n3 3 2\n1 1\n3 2\n",
+        )
+        .unwrap();
+
+        // [[1, 0, 0], [0, 0, 1], [0, 1, 0]]
+        let csr: CsrMatrix<i32, f64> = CsrMatrix::from_matrix_market(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(csr.indices, vec![0, 2, 1]);
+        assert_eq!(csr.indptr, vec![0, 1, 2, 3]);
+        assert_eq!(csr.data, vec![1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_matrix_market_invalid_banner_does_not_panic() {
+        let path = mm_test_path("invalid_banner");
+        std::fs::write(&path, "not a matrix market file\n").unwrap();
+
+        let result: Result<CsrMatrix<i32, f64>, _> = CsrMatrix::from_matrix_market(&path);
+        std::fs::remove_file(&path).ok();
+
+        assert!(matches!(result, Err(MatrixMarketError::InvalidBanner(_))));
+    }
+
+    #[test]
+    fn test_matrix_market_out_of_range_row_is_rejected() {
+        let path = mm_test_path("out_of_range_row");
+        // Header declares 2 rows, but the entry is on row 5.
+        std::fs::write(
+            &path,
+            "%%MatrixMarket matrix coordinate real general\n2 3 1\n5 1 3.0\n",
+        )
+        .unwrap();
+
+        let result: Result<CsrMatrix<i32, f64>, _> = CsrMatrix::from_matrix_market(&path);
+        std::fs::remove_file(&path).ok();
+
+        assert!(matches!(result, Err(MatrixMarketError::InvalidEntry(_))));
+    }
+
+    #[test]
+    fn test_matrix_market_out_of_range_col_is_rejected() {
+        let path = mm_test_path("out_of_range_col");
+        // Header declares 2 columns, but the entry is on column 5.
+        std::fs::write(
+            &path,
+            "%%MatrixMarket matrix coordinate real general\n3 2 1\n1 5 3.0\n",
+        )
+        .unwrap();
+
+        let result: Result<CsrMatrix<i32, f64>, _> = CsrMatrix::from_matrix_market(&path);
+        std::fs::remove_file(&path).ok();
+
+        assert!(matches!(result, Err(MatrixMarketError::InvalidEntry(_))));
+    }
+
+    #[test]
+    fn test_matmul_against_dense_reference() {
+        // A (2x3) = [[1, 0, 2], [0, 0, 0]]
+        let a = CsrMatrix {
+            indices: vec![0, 2],
+            indptr: vec![0, 2, 2],
+            data: vec![1, 2],
+        };
+        // B (3x2) = [[1, 2], [0, 1], [3, 0]]
+        let b = CsrMatrix {
+            indices: vec![0, 1, 1, 0],
+            indptr: vec![0, 2, 3, 4],
+            data: vec![1, 2, 1, 3],
+        };
+
+        // A*B = [[1*1 + 2*3, 1*2 + 2*0], [0, 0]] = [[7, 2], [0, 0]]
+        let result = a.matmul(&b, 2);
+        assert_eq!(result.indices, vec![0, 1]);
+        assert_eq!(result.indptr, vec![0, 2, 2]);
+        assert_eq!(result.data, vec![7, 2]);
+    }
+
+    #[test]
+    fn test_matmul_empty_row() {
+        // A (1x2) with an all-zero row.
+        let a: CsrMatrix<i32, i32> = CsrMatrix {
+            indices: vec![],
+            indptr: vec![0, 0],
+            data: vec![],
+        };
+        // B (2x2) = [[1, 2], [3, 4]]
+        let b = CsrMatrix {
+            indices: vec![0, 1, 0, 1],
+            indptr: vec![0, 2, 4],
+            data: vec![1, 2, 3, 4],
+        };
+
+        let result = a.matmul(&b, 2);
+        assert!(result.indices.is_empty());
+        assert_eq!(result.indptr, vec![0, 0]);
+        assert!(result.data.is_empty());
+    }
+
+    #[test]
+    fn test_validate_accepts_well_formed_matrix() {
+        // [[1, 0, 2], [0, 0, 0], [0, 3, 0]]
+        let csr = CsrMatrix {
+            indices: vec![0, 2, 1],
+            indptr: vec![0, 2, 2, 3],
+            data: vec![1, 2, 3],
+        };
+        assert!(csr.validate(3).is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_decreasing_indptr() {
+        let csr = CsrMatrix {
+            indices: vec![0, 1],
+            indptr: vec![0, 2, 1],
+            data: vec![1, 2],
+        };
+        assert_eq!(
+            csr.validate(2),
+            Err(StructureError::IndptrNotNonDecreasing)
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_length_mismatch() {
+        let csr = CsrMatrix {
+            indices: vec![0],
+            indptr: vec![0, 1, 2],
+            data: vec![1],
+        };
+        assert_eq!(csr.validate(2), Err(StructureError::IndptrLengthMismatch));
+    }
+
+    #[test]
+    fn test_validate_rejects_out_of_bounds_column() {
+        let csr = CsrMatrix {
+            indices: vec![5],
+            indptr: vec![0, 1],
+            data: vec![1],
+        };
+        assert_eq!(csr.validate(3), Err(StructureError::ColumnOutOfBounds));
+    }
+
+    #[test]
+    fn test_validate_rejects_non_increasing_columns() {
+        let csr = CsrMatrix {
+            indices: vec![1, 0],
+            indptr: vec![0, 2],
+            data: vec![1, 2],
+        };
+        assert_eq!(csr.validate(2), Err(StructureError::ColumnsNotIncreasing));
+    }
+}
+
+#[cfg(all(test, feature = "proptest"))]
+mod proptest_tests {
+    use proptest::prelude::*;
+
+    use super::arbitrary::arb_csr_matrix;
+    use super::CsrMatrix;
+
+    fn csr_eq(a: &CsrMatrix<i32, i32>, b: &CsrMatrix<i32, i32>) -> bool {
+        a.indices == b.indices && a.indptr == b.indptr && a.data == b.data
+    }
+
+    proptest! {
+        #[test]
+        fn coo_csr_roundtrip(matrix in arb_csr_matrix(8, 8)) {
+            let n_cols = 8;
+            let roundtrip = matrix.to_coo(n_cols).to_csr();
+            prop_assert!(csr_eq(&matrix, &roundtrip));
+        }
+
+        #[test]
+        fn dok_csr_roundtrip(matrix in arb_csr_matrix(8, 8)) {
+            let n_rows = matrix.n_rows();
+            let roundtrip = matrix.to_dok(Some(n_rows)).to_csr();
+            prop_assert!(csr_eq(&matrix, &roundtrip));
+        }
+
+        #[test]
+        fn add_is_commutative(a in arb_csr_matrix(8, 8), b in arb_csr_matrix(8, 8)) {
+            let n_rows = a.n_rows().max(b.n_rows());
+            let left = CsrMatrix::add(&a, &b, Some(n_rows));
+            let right = CsrMatrix::add(&b, &a, Some(n_rows));
+            prop_assert!(csr_eq(&left, &right));
+        }
+
+        #[test]
+        fn add_is_associative(
+            a in arb_csr_matrix(6, 6),
+            b in arb_csr_matrix(6, 6),
+            c in arb_csr_matrix(6, 6),
+        ) {
+            let n_rows = a.n_rows().max(b.n_rows()).max(c.n_rows());
+            let ab = CsrMatrix::add(&a, &b, Some(n_rows));
+            let left = CsrMatrix::add(&ab, &c, Some(n_rows));
+            let bc = CsrMatrix::add(&b, &c, Some(n_rows));
+            let right = CsrMatrix::add(&a, &bc, Some(n_rows));
+            prop_assert!(csr_eq(&left, &right));
+        }
+    }
 }